@@ -0,0 +1,48 @@
+use crate::cli::ToArgs;
+use arbitrary::Arbitrary;
+use facet::Facet;
+use figue as args;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+/// Global arguments available to every subcommand.
+#[derive(Facet, Arbitrary, Debug, PartialEq, Default)]
+pub struct GlobalArgs {
+    /// Enable debug logging.
+    #[facet(args::named, default)]
+    pub debug: bool,
+
+    /// Override the log filter (e.g. `RUST_LOG`-style directives).
+    #[facet(args::named)]
+    pub log_filter: Option<String>,
+
+    /// Write logs to this file instead of stderr.
+    #[facet(args::named)]
+    pub log_file: Option<PathBuf>,
+
+    /// Preview mode for every subcommand that supports it. For `sync`, this
+    /// has the same effect as passing `--dry-run` directly to `sync`.
+    #[facet(args::named, default)]
+    pub dry_run: bool,
+}
+
+impl ToArgs for GlobalArgs {
+    fn to_args(&self) -> Vec<OsString> {
+        let mut args = Vec::new();
+        if self.debug {
+            args.push("--debug".into());
+        }
+        if let Some(log_filter) = &self.log_filter {
+            args.push("--log-filter".into());
+            args.push(log_filter.clone().into());
+        }
+        if let Some(log_file) = &self.log_file {
+            args.push("--log-file".into());
+            args.push(log_file.as_os_str().into());
+        }
+        if self.dry_run {
+            args.push("--dry-run".into());
+        }
+        args
+    }
+}
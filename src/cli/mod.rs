@@ -32,7 +32,7 @@ impl<T: ToArgs> ToArgs for &T {
 /// A demonstration command line utility.
 #[derive(Facet, Arbitrary, Debug)]
 pub struct Cli {
-    /// Global arguments (`debug`, `log_filter`, `log_file`).
+    /// Global arguments (`debug`, `log_filter`, `log_file`, `dry_run`).
     #[facet(flatten)]
     pub global: GlobalArgs,
 
@@ -62,7 +62,8 @@ impl Cli {
             .enable_all()
             .build()
             .wrap_err("Failed to build tokio runtime")?;
-        runtime.block_on(async move { self.command.invoke().await })?;
+        let global_dry_run = self.global.dry_run;
+        runtime.block_on(async move { self.command.invoke(global_dry_run).await })?;
         Ok(())
     }
 }
@@ -95,11 +96,11 @@ impl Command {
     /// # Errors
     ///
     /// This function will return an error if the subcommand fails.
-    pub async fn invoke(self) -> eyre::Result<()> {
+    pub async fn invoke(self, global_dry_run: bool) -> eyre::Result<()> {
         match self {
             Command::ReadRepo(args) => args.invoke().await,
             Command::ThisRepo(args) => args.invoke().await,
-            Command::Sync(args) => args.invoke().await,
+            Command::Sync(args) => args.invoke(global_dry_run).await,
         }
     }
 }
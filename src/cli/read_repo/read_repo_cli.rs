@@ -5,7 +5,7 @@ use eyre::Result;
 use facet::Facet;
 use figue as args;
 use std::ffi::OsString;
-use std::path::PathBuf;
+use std::path::Path;
 
 #[derive(Facet, Arbitrary, Debug, PartialEq)]
 pub struct ReadRepoArgs {
@@ -20,10 +20,20 @@ pub enum ReadRepoCommand {
     List(ReadRepoListArgs),
 }
 
+/// Accepts either a local path to an existing git repository or a clone URL
+/// (`https://`, `ssh://`, or `git@host:owner/repo`). URLs are cloned/fetched
+/// into a managed cache directory as part of `sync` rather than immediately.
 #[derive(Facet, Arbitrary, Debug, PartialEq)]
 pub struct ReadRepoAddArgs {
     #[facet(args::positional)]
-    pub path: PathBuf,
+    pub path_or_url: String,
+}
+
+fn is_clone_url(value: &str) -> bool {
+    value.starts_with("https://")
+        || value.starts_with("http://")
+        || value.starts_with("ssh://")
+        || value.starts_with("git@")
 }
 
 #[derive(Facet, Arbitrary, Debug, PartialEq, Default)]
@@ -38,15 +48,24 @@ impl ReadRepoArgs {
             match self.command {
                 ReadRepoCommand::Add(args) => {
                     let mut config = VanityConfig::load()?;
-                    let canonical = config.add_read_repo(&args.path)?;
-                    config.save()?;
-                    println!("{}", canonical.display());
+                    if is_clone_url(&args.path_or_url) {
+                        let spec = config.add_read_repo_url(&args.path_or_url);
+                        config.save()?;
+                        println!("{}", spec.url);
+                    } else {
+                        let canonical = config.add_read_repo(Path::new(&args.path_or_url))?;
+                        config.save()?;
+                        println!("{}", canonical.display());
+                    }
                 }
                 ReadRepoCommand::List(_) => {
                     let config = VanityConfig::load()?;
                     for repo in config.read_repos {
                         println!("{}", repo.display());
                     }
+                    for spec in config.read_repo_specs {
+                        println!("{} (cache: {})", spec.url, spec.cache_dir.display());
+                    }
                 }
             }
             Ok(())
@@ -62,7 +81,7 @@ impl ToArgs for ReadRepoArgs {
         match &self.command {
             ReadRepoCommand::Add(add) => {
                 args.push("add".into());
-                args.push(add.path.as_os_str().into());
+                args.push(add.path_or_url.clone().into());
             }
             ReadRepoCommand::List(_) => {
                 args.push("list".into());
@@ -1,21 +1,30 @@
 use crate::cli::ToArgs;
-use crate::vanity::VanityConfig;
+use crate::vanity::{SyncSummary, VanityConfig};
 use arbitrary::Arbitrary;
 use eyre::Result;
 use facet::Facet;
 use figue as args;
 use std::ffi::OsString;
+use std::time::Duration;
 
 /// Synchronize vanity commits from configured source repositories.
 ///
 /// What this command reads:
 /// - `this-repo` from config (target repository where vanity commits are written)
-/// - all repositories listed in `read-repo list` (source commit history)
+/// - all repositories listed in `read-repo list` (source commit history),
+///   read concurrently up to `--concurrency` at a time so one slow repo
+///   doesn't stall the others; see also `--repo-timeout-secs`
+/// - URL-backed read-repos are cloned/fetched into a managed cache directory
+///   under `APP_HOME` first, so they always reflect the latest upstream refs;
+///   `--dry-run` skips this and previews against whatever is already cached
+///   (a spec that's never been synced has nothing to preview)
 ///
 /// What this command writes:
 /// - only to `this-repo` (unless `--dry_run`)
 /// - creates empty commits whose messages contain source metadata, including
 ///   `Vanity-Source-Commit: <sha>` and source commit URL when derivable
+/// - with `--push`, also pushes `this-repo`'s current branch to `origin`
+/// - updates the per-repo `sync-state.txt` commit cache (unless `--dry-run`)
 ///
 /// Idempotency:
 /// - if a source sha marker already exists in current `this-repo` HEAD history,
@@ -26,9 +35,17 @@ use std::ffi::OsString;
 /// - does not rewrite existing commits
 /// - does not modify read-repo history
 /// - normal mode enforces origin safety (must match TeamDman/Vanity)
+///
+/// With `--watch`, the command stays resident instead of exiting after one
+/// pass: it re-reads every read-repo on a fixed interval, creates vanity
+/// commits for anything newly discovered, and prints a summary per cycle.
+/// Ctrl-C stops the loop cleanly between cycles (or immediately if pressed
+/// while idle).
 #[derive(Facet, Arbitrary, Debug, PartialEq, Default)]
 pub struct SyncArgs {
     /// Preview mode. Computes pending vanity commits but does not create any commits.
+    ///
+    /// Also enabled by the global `--dry-run` flag.
     #[facet(args::named, default)]
     pub dry_run: bool,
 
@@ -43,30 +60,171 @@ pub struct SyncArgs {
     /// Use only for intentional testing in another repo.
     #[facet(args::named, default)]
     pub allow_non_vanity_target: bool,
+
+    /// Override which author identity to mirror for this run.
+    ///
+    /// Accepts an email (matched case-insensitively) or a name regex. Takes
+    /// precedence over configured `author=` lines; if neither is set, falls
+    /// back to the resolved `user.email` of `this-repo`.
+    #[facet(args::named)]
+    pub author: Option<String>,
+
+    /// After creating vanity commits, push `this-repo`'s current branch to `origin`.
+    ///
+    /// Ignored in `--dry-run` mode. Supports ssh-agent and HTTPS credential helper auth.
+    #[facet(args::named, default)]
+    pub push: bool,
+
+    /// Output format for the sync summary.
+    ///
+    /// `json` emits the full sync plan as a machine-readable array, suitable
+    /// for driving CI decisions without parsing log lines.
+    #[facet(args::named, default)]
+    pub message_format: MessageFormat,
+
+    /// Stay resident and re-sync on a fixed interval instead of exiting after
+    /// one pass. See `--poll-interval` to control the cadence.
+    #[facet(args::named, default)]
+    pub watch: bool,
+
+    /// Seconds between polling cycles in `--watch` mode. Defaults to 30.
+    #[facet(args::named)]
+    pub poll_interval: Option<u64>,
+
+    /// Maximum number of read-repos to read concurrently.
+    ///
+    /// Defaults to the number of available CPU cores.
+    #[facet(args::named)]
+    pub concurrency: Option<usize>,
+
+    /// Abort reading a single read-repo after this many seconds instead of
+    /// letting a slow or unreachable repo stall the whole sync.
+    #[facet(args::named)]
+    pub repo_timeout_secs: Option<u64>,
+
+    /// Keep reading the remaining read-repos after one fails instead of
+    /// aborting immediately. Failures are tallied and reported at the end,
+    /// and the command still exits with an error if any repo failed.
+    /// A failed clone or fetch of a URL-backed read-repo is not tallied this
+    /// way: it aborts the run immediately even with this flag.
+    ///
+    /// Failures that prevent the run from starting at all (e.g. `this-repo`
+    /// missing) always abort immediately regardless of this flag.
+    #[facet(args::named, default)]
+    pub no_fail_fast: bool,
+}
+
+/// Output format for `sync`'s summary.
+#[derive(Facet, Arbitrary, Debug, PartialEq, Default, Clone, Copy)]
+#[repr(u8)]
+pub enum MessageFormat {
+    /// Human-readable summary lines (default).
+    #[default]
+    Human,
+    /// A JSON array of sync plan entries.
+    Json,
 }
 
 impl SyncArgs {
     /// # Errors
     ///
     /// Returns an error if config is invalid or synchronization fails.
-    pub async fn invoke(self) -> Result<()> {
-        let summary = tokio::task::spawn_blocking(move || -> Result<_> {
+    pub async fn invoke(self, global_dry_run: bool) -> Result<()> {
+        let dry_run = self.dry_run || global_dry_run;
+        if self.watch {
+            return self.invoke_watch(dry_run).await;
+        }
+        let summary = self.run_once(dry_run).await?;
+        self.print_summary(dry_run, &summary);
+        if !summary.read_repo_failures.is_empty() {
+            eyre::bail!(
+                "{} of {} read-repos failed",
+                summary.read_repo_failures.len(),
+                summary.read_repos_total
+            );
+        }
+        Ok(())
+    }
+
+    /// Runs `sync` repeatedly on a fixed interval until Ctrl-C is pressed.
+    async fn invoke_watch(&self, dry_run: bool) -> Result<()> {
+        let poll_interval = Duration::from_secs(self.poll_interval.unwrap_or(30));
+        println!(
+            "[WATCH] polling every {}s, press Ctrl-C to stop",
+            poll_interval.as_secs()
+        );
+        loop {
+            let summary = tokio::select! {
+                result = self.run_once(dry_run) => result?,
+                _ = tokio::signal::ctrl_c() => {
+                    println!("[WATCH] received Ctrl-C, stopping");
+                    return Ok(());
+                }
+            };
+            self.print_summary(dry_run, &summary);
+            tokio::select! {
+                () = tokio::time::sleep(poll_interval) => {}
+                _ = tokio::signal::ctrl_c() => {
+                    println!("[WATCH] received Ctrl-C, stopping");
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Runs one synchronization pass against the configured read-repos.
+    async fn run_once(&self, dry_run: bool) -> Result<SyncSummary> {
+        let allow_non_vanity_target = self.allow_non_vanity_target;
+        let limit = self.limit;
+        let author = self.author.clone();
+        let push = self.push;
+        let concurrency = self.concurrency;
+        let repo_timeout = self.repo_timeout_secs.map(Duration::from_secs);
+        let no_fail_fast = self.no_fail_fast;
+        tokio::task::spawn_blocking(move || -> Result<_> {
             let config = VanityConfig::load()?;
             crate::vanity::sync(
                 &config,
-                self.dry_run,
-                self.allow_non_vanity_target,
-                self.limit,
+                dry_run,
+                allow_non_vanity_target,
+                limit,
+                author.as_deref(),
+                push,
+                concurrency,
+                repo_timeout,
+                no_fail_fast,
             )
         })
         .await
-        .map_err(|err| eyre::eyre!("sync task failed: {err}"))??;
-        let mode = if self.dry_run { "DRY RUN" } else { "APPLY" };
-        println!(
-            "[{mode}] source_commits={} mirrored_markers={} newly_created={}",
-            summary.total_source_commits, summary.existing_markers, summary.created
-        );
-        Ok(())
+        .map_err(|err| eyre::eyre!("sync task failed: {err}"))?
+    }
+
+    fn print_summary(&self, dry_run: bool, summary: &SyncSummary) {
+        match self.message_format {
+            MessageFormat::Json => {
+                println!("{}", crate::vanity::plan_entries_to_json(&summary.entries));
+            }
+            MessageFormat::Human => {
+                let mode = if dry_run { "DRY RUN" } else { "APPLY" };
+                println!(
+                    "[{mode}] source_commits={} mirrored_markers={} newly_created={}",
+                    summary.total_source_commits, summary.existing_markers, summary.created
+                );
+                if let Some(pushed_ref) = &summary.pushed_ref {
+                    println!("[{mode}] pushed {pushed_ref} to origin");
+                }
+                if !summary.read_repo_failures.is_empty() {
+                    println!(
+                        "[{mode}] {} of {} read-repos failed",
+                        summary.read_repo_failures.len(),
+                        summary.read_repos_total
+                    );
+                    for failure in &summary.read_repo_failures {
+                        println!("[{mode}]   {}: {}", failure.read_repo, failure.message);
+                    }
+                }
+            }
+        }
     }
 }
 
@@ -83,6 +241,35 @@ impl ToArgs for SyncArgs {
         if self.allow_non_vanity_target {
             args.push("--allow-non-vanity-target".into());
         }
+        if let Some(author) = &self.author {
+            args.push("--author".into());
+            args.push(author.clone().into());
+        }
+        if self.push {
+            args.push("--push".into());
+        }
+        if self.message_format == MessageFormat::Json {
+            args.push("--message-format".into());
+            args.push("json".into());
+        }
+        if self.watch {
+            args.push("--watch".into());
+        }
+        if let Some(poll_interval) = self.poll_interval {
+            args.push("--poll-interval".into());
+            args.push(poll_interval.to_string().into());
+        }
+        if let Some(concurrency) = self.concurrency {
+            args.push("--concurrency".into());
+            args.push(concurrency.to_string().into());
+        }
+        if let Some(repo_timeout_secs) = self.repo_timeout_secs {
+            args.push("--repo-timeout-secs".into());
+            args.push(repo_timeout_secs.to_string().into());
+        }
+        if self.no_fail_fast {
+            args.push("--no-fail-fast".into());
+        }
         args
     }
 }
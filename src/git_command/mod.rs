@@ -0,0 +1,219 @@
+//! A shared builder for shelling out to the `git` binary directly.
+//!
+//! Reading and writing commits stays on `git2` bindings (see
+//! `gather_source_commits_for_repo`, `create_empty_commit`), since those are
+//! pure local object-database operations `git2` handles well. `GitCommand`
+//! is for operations that talk to a remote or need the system git's own
+//! credential resolution (ssh-agent, credential helpers) instead of
+//! reimplementing it: `read_repo add`/`this_repo set` validate a path is a
+//! git repository via a `rev-parse` probe (see
+//! `crate::vanity::canonicalize_git_repo`), and `sync` clones/fetches
+//! read-repos and pushes `this-repo` through it (see
+//! `crate::vanity::clone_or_fetch_read_repo`, `crate::vanity::push_this_repo`).
+//! [`check_git_available`] is called once up front by `sync` so a missing
+//! `git` binary fails fast with one clear error instead of surfacing from
+//! whichever of those operations happens to run first.
+
+use eyre::Result;
+use std::ffi::OsStr;
+use std::ffi::OsString;
+use std::panic::Location;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::ExitStatus;
+use std::process::Stdio;
+
+/// What to do when the process exits with a non-zero status.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum FailureMode {
+    /// Treat a non-zero exit status as an error.
+    #[default]
+    Exit,
+    /// Return the output regardless of exit status.
+    Ignore,
+}
+
+/// Whether to capture a stream in memory or let it inherit the parent's.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum StreamMode {
+    /// Capture the stream and make it available on `GitCommandOutput`.
+    #[default]
+    Capture,
+    /// Let the child print directly to the parent's stream.
+    Print,
+}
+
+/// The result of running a [`GitCommand`].
+#[derive(Debug)]
+pub struct GitCommandOutput {
+    pub status: ExitStatus,
+    pub stdout: Option<String>,
+    pub stderr: Option<String>,
+}
+
+/// A builder for invoking the `git` binary with uniform, traceable error
+/// reporting: failures include both where the command was constructed and
+/// where it was run, plus any captured output.
+///
+/// Holds a "drop bomb": a `GitCommand` that is constructed but never [`run`]
+/// panics when dropped (outside of unwinding), to catch a planned git
+/// operation that silently falls out of scope.
+///
+/// [`run`]: GitCommand::run
+#[derive(Debug)]
+pub struct GitCommand {
+    program: OsString,
+    args: Vec<OsString>,
+    current_dir: Option<PathBuf>,
+    failure_mode: FailureMode,
+    stdout_mode: StreamMode,
+    stderr_mode: StreamMode,
+    created_at: &'static Location<'static>,
+    executed: bool,
+}
+
+impl GitCommand {
+    /// Starts a new invocation of `program` (usually `"git"`).
+    #[track_caller]
+    pub fn new(program: impl Into<OsString>) -> Self {
+        Self {
+            program: program.into(),
+            args: Vec::new(),
+            current_dir: None,
+            failure_mode: FailureMode::default(),
+            stdout_mode: StreamMode::default(),
+            stderr_mode: StreamMode::default(),
+            created_at: Location::caller(),
+            executed: false,
+        }
+    }
+
+    #[must_use]
+    pub fn arg(mut self, arg: impl AsRef<OsStr>) -> Self {
+        self.args.push(arg.as_ref().to_os_string());
+        self
+    }
+
+    #[must_use]
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        self.args.extend(args.into_iter().map(|arg| arg.as_ref().to_os_string()));
+        self
+    }
+
+    #[must_use]
+    pub fn current_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.current_dir = Some(dir.into());
+        self
+    }
+
+    #[must_use]
+    pub fn failure_mode(mut self, mode: FailureMode) -> Self {
+        self.failure_mode = mode;
+        self
+    }
+
+    #[must_use]
+    pub fn stdout_mode(mut self, mode: StreamMode) -> Self {
+        self.stdout_mode = mode;
+        self
+    }
+
+    #[must_use]
+    pub fn stderr_mode(mut self, mode: StreamMode) -> Self {
+        self.stderr_mode = mode;
+        self
+    }
+
+    /// Runs the command.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the process cannot be spawned, or if
+    /// `failure_mode` is [`FailureMode::Exit`] (the default) and the process
+    /// exits non-zero. Either error names the source location where the
+    /// command was constructed and where it was run, plus any captured
+    /// stdout/stderr.
+    #[track_caller]
+    pub fn run(mut self) -> Result<GitCommandOutput> {
+        let executed_at = Location::caller();
+        self.executed = true;
+
+        let mut command = Command::new(&self.program);
+        command.args(&self.args);
+        if let Some(dir) = &self.current_dir {
+            command.current_dir(dir);
+        }
+        command.stdout(stdio_for(self.stdout_mode));
+        command.stderr(stdio_for(self.stderr_mode));
+
+        let output = command.output().map_err(|err| {
+            eyre::eyre!(
+                "Failed to spawn `{}` (constructed at {}, run at {executed_at}): {err}",
+                self.command_line(),
+                self.created_at,
+            )
+        })?;
+
+        let stdout = (self.stdout_mode == StreamMode::Capture)
+            .then(|| String::from_utf8_lossy(&output.stdout).into_owned());
+        let stderr = (self.stderr_mode == StreamMode::Capture)
+            .then(|| String::from_utf8_lossy(&output.stderr).into_owned());
+
+        if self.failure_mode == FailureMode::Exit && !output.status.success() {
+            eyre::bail!(
+                "`{}` exited with {} (constructed at {}, run at {executed_at})\nstdout: {}\nstderr: {}",
+                self.command_line(),
+                output.status,
+                self.created_at,
+                stdout.as_deref().unwrap_or("<not captured>"),
+                stderr.as_deref().unwrap_or("<not captured>"),
+            );
+        }
+
+        Ok(GitCommandOutput {
+            status: output.status,
+            stdout,
+            stderr,
+        })
+    }
+
+    fn command_line(&self) -> String {
+        let mut parts = vec![self.program.to_string_lossy().into_owned()];
+        parts.extend(self.args.iter().map(|arg| arg.to_string_lossy().into_owned()));
+        parts.join(" ")
+    }
+}
+
+impl Drop for GitCommand {
+    fn drop(&mut self) {
+        if !self.executed && !std::thread::panicking() {
+            panic!(
+                "GitCommand `{}` constructed at {} was dropped without being run",
+                self.command_line(),
+                self.created_at
+            );
+        }
+    }
+}
+
+fn stdio_for(mode: StreamMode) -> Stdio {
+    match mode {
+        StreamMode::Capture => Stdio::piped(),
+        StreamMode::Print => Stdio::inherit(),
+    }
+}
+
+/// Verifies that a system `git` binary is on `PATH`, returning its reported
+/// version string.
+///
+/// # Errors
+///
+/// Returns an error if `git` cannot be found or exits non-zero.
+pub fn check_git_available() -> Result<String> {
+    let output = GitCommand::new("git").arg("--version").run()?;
+    Ok(output.stdout.unwrap_or_default().trim().to_owned())
+}
@@ -1,3 +1,7 @@
+use crate::git_command::FailureMode;
+use crate::git_command::GitCommand;
+use crate::git_command::GitCommandOutput;
+use crate::git_command::StreamMode;
 use crate::paths::APP_HOME;
 use chrono::FixedOffset;
 use chrono::TimeZone;
@@ -12,18 +16,44 @@ use git2::Sort;
 use indicatif::ProgressBar;
 use indicatif::ProgressStyle;
 use rayon::prelude::*;
+use regex::Regex;
 use std::collections::HashSet;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
 
 const MARKER_PREFIX: &str = "Vanity-Source-Commit: ";
 const EXPECTED_VANITY_REMOTE: &str = "https://github.com/TeamDman/Vanity";
 const CONFIG_FILENAME: &str = "vanity-config.txt";
+const SYNC_STATE_FILENAME: &str = "sync-state.txt";
 
 #[derive(Clone, Debug, Default)]
 pub struct VanityConfig {
     pub this_repo: Option<PathBuf>,
     pub read_repos: Vec<PathBuf>,
+    /// Author identities to mirror: emails (matched case-insensitively) and/or
+    /// name regexes. Empty means "fall back to the resolved `user.email`".
+    pub authors: Vec<String>,
+    /// URL-backed read-repos that are cloned/fetched into a managed cache
+    /// directory under `APP_HOME` instead of being read from a local path.
+    pub read_repo_specs: Vec<RepoSpec>,
+    /// User-configured `(host, url-template)` overrides for forges that
+    /// aren't recognized automatically. The template is appended to the
+    /// detected web base URL with `{sha}` substituted for the commit sha.
+    pub forges: Vec<(String, String)>,
+}
+
+/// A read-repo that is tracked by clone URL instead of a local path.
+///
+/// `cache_dir` is derived deterministically from `url` and is where `sync`
+/// clones (first run) or fetches (subsequent runs) the mirror before it is
+/// walked like any other read-repo.
+#[derive(Clone, Debug)]
+pub struct RepoSpec {
+    pub url: String,
+    pub cache_dir: PathBuf,
 }
 
 #[derive(Clone, Debug)]
@@ -31,6 +61,70 @@ pub struct SyncSummary {
     pub total_source_commits: usize,
     pub existing_markers: usize,
     pub created: usize,
+    /// The branch that was pushed to origin, if `--push` was requested.
+    pub pushed_ref: Option<String>,
+    /// The full sync plan, one entry per discovered source commit, suitable
+    /// for machine-readable output (see `--message-format json`).
+    pub entries: Vec<SyncPlanEntry>,
+    /// Number of read-repos that were attempted.
+    pub read_repos_total: usize,
+    /// Read-repos that failed to read. Always empty unless `--no-fail-fast`
+    /// was passed, since otherwise the first failure aborts `sync` entirely.
+    pub read_repo_failures: Vec<ReadRepoFailure>,
+}
+
+/// A read-repo that failed to read under `--no-fail-fast`.
+#[derive(Clone, Debug)]
+pub struct ReadRepoFailure {
+    pub read_repo: String,
+    pub message: String,
+}
+
+/// One source commit's place in the sync plan: what would be mirrored, and
+/// whether it already has been.
+#[derive(Clone, Debug)]
+pub struct SyncPlanEntry {
+    pub read_repo: String,
+    pub source_commit_sha: String,
+    pub source_commit_date: String,
+    pub planned_vanity_commit: String,
+    pub already_present: bool,
+}
+
+/// Serializes sync plan entries to a JSON array for `--message-format json`.
+pub fn plan_entries_to_json(entries: &[SyncPlanEntry]) -> String {
+    let items: Vec<String> = entries
+        .iter()
+        .map(|entry| {
+            format!(
+                "{{\"read_repo\":{},\"source_commit_sha\":{},\"source_commit_date\":{},\"planned_vanity_commit\":{},\"already_present\":{}}}",
+                json_escape(&entry.read_repo),
+                json_escape(&entry.source_commit_sha),
+                json_escape(&entry.source_commit_date),
+                json_escape(&entry.planned_vanity_commit),
+                entry.already_present
+            )
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+fn json_escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
 }
 
 #[derive(Clone, Debug)]
@@ -38,6 +132,48 @@ struct SourceCommit {
     sha: String,
     source_repo_hint: String,
     source_web_base_url: Option<String>,
+    forge_kind: ForgeKind,
+    author_date_seconds: i64,
+    author_offset_minutes: i32,
+    subject: String,
+}
+
+/// The forge hosting a read-repo's origin, used to format commit URLs.
+///
+/// `Custom` holds a user-configured `forge=<host>=<url-template>` template
+/// (see [`VanityConfig::forges`]) for hosts that don't match a known forge.
+#[derive(Clone, Debug, Default, PartialEq)]
+enum ForgeKind {
+    #[default]
+    GitHub,
+    GitLab,
+    Gitea,
+    Bitbucket,
+    Custom(String),
+}
+
+/// Persisted per-read-repo sync state, keyed by canonical path, so
+/// `gather_source_commits_for_repo` can skip the revwalk entirely when a
+/// repo's ref tips haven't moved since the last sync.
+///
+/// `commits` is the *unfiltered* set of every commit reachable from `tips` at
+/// the time of caching, not just the ones matching that run's author
+/// patterns: the configured author can change between runs on unmoved tips
+/// (`--author` override, edited `author=` lines), so filtering must happen on
+/// every read rather than being baked into what gets cached.
+#[derive(Clone, Debug)]
+struct RepoCacheEntry {
+    canonical_path: PathBuf,
+    origin_hint: String,
+    tips: Vec<Oid>,
+    commits: Vec<CachedSourceCommit>,
+}
+
+#[derive(Clone, Debug)]
+struct CachedSourceCommit {
+    sha: String,
+    author_email: Option<String>,
+    author_name: Option<String>,
     author_date_seconds: i64,
     author_offset_minutes: i32,
     subject: String,
@@ -65,6 +201,24 @@ impl VanityConfig {
             }
             if let Some(value) = line.strip_prefix("read=") {
                 config.read_repos.push(PathBuf::from(value));
+                continue;
+            }
+            if let Some(value) = line.strip_prefix("author=") {
+                config.authors.push(value.to_owned());
+                continue;
+            }
+            if let Some(value) = line.strip_prefix("read-url=") {
+                let url = value.to_owned();
+                let cache_dir = read_repo_cache_dir(&url);
+                config.read_repo_specs.push(RepoSpec { url, cache_dir });
+                continue;
+            }
+            if let Some(value) = line.strip_prefix("forge=") {
+                if let Some((host, template)) = value.split_once('=') {
+                    config
+                        .forges
+                        .push((host.to_lowercase(), template.to_owned()));
+                }
             }
         }
         Ok(config)
@@ -83,6 +237,15 @@ impl VanityConfig {
         for read_repo in &self.read_repos {
             lines.push(format!("read={}", read_repo.display()));
         }
+        for author in &self.authors {
+            lines.push(format!("author={author}"));
+        }
+        for spec in &self.read_repo_specs {
+            lines.push(format!("read-url={}", spec.url));
+        }
+        for (host, template) in &self.forges {
+            lines.push(format!("forge={host}={template}"));
+        }
         std::fs::write(&path, lines.join("\n"))
             .wrap_err_with(|| format!("Failed to write config file {}", path.display()))
     }
@@ -110,6 +273,26 @@ impl VanityConfig {
         }
         Ok(canonical)
     }
+
+    /// Adds a URL-backed read-repo, deriving its managed cache directory.
+    ///
+    /// Does not clone or fetch; that happens as part of `sync`.
+    pub fn add_read_repo_url(&mut self, url: &str) -> RepoSpec {
+        let normalized = url.trim().to_owned();
+        if let Some(existing) = self
+            .read_repo_specs
+            .iter()
+            .find(|spec| spec.url == normalized)
+        {
+            return existing.clone();
+        }
+        let spec = RepoSpec {
+            cache_dir: read_repo_cache_dir(&normalized),
+            url: normalized,
+        };
+        self.read_repo_specs.push(spec.clone());
+        spec
+    }
 }
 
 /// # Errors
@@ -120,13 +303,21 @@ pub fn sync(
     dry_run: bool,
     allow_non_vanity_target: bool,
     limit: Option<usize>,
+    author_override: Option<&str>,
+    push: bool,
+    concurrency: Option<usize>,
+    per_repo_timeout: Option<Duration>,
+    no_fail_fast: bool,
 ) -> Result<SyncSummary> {
     let Some(this_repo_path) = &config.this_repo else {
         bail!("this-repo is not configured. Run: this-repo set <path>");
     };
-    if config.read_repos.is_empty() {
+    if config.read_repos.is_empty() && config.read_repo_specs.is_empty() {
         bail!("read-repo list is empty. Run: read-repo add <path>");
     }
+    crate::git_command::check_git_available().wrap_err(
+        "No working `git` binary found on PATH (required alongside the git2 bindings this crate otherwise uses)",
+    )?;
 
     let this_repo = Repository::open(this_repo_path)
         .wrap_err_with(|| format!("Failed to open this-repo at {}", this_repo_path.display()))?;
@@ -135,8 +326,25 @@ pub fn sync(
         assert_vanity_target_repo(&this_repo, allow_non_vanity_target)?;
     }
 
+    let mut read_repos = config.read_repos.clone();
+    read_repos.extend(if dry_run {
+        dry_run_read_repos(&config.read_repo_specs)
+    } else {
+        sync_remote_read_repos(&config.read_repo_specs)?
+    });
+
     let existing_markers = existing_mirrored_shas(&this_repo)?;
-    let source_commits = gather_source_commits(&config.read_repos)?;
+    let author_patterns = resolve_author_patterns(config, author_override, &this_repo);
+    let read_repos_total = read_repos.len();
+    let (source_commits, read_repo_failures) = gather_source_commits(
+        &read_repos,
+        &author_patterns,
+        &config.forges,
+        concurrency,
+        per_repo_timeout,
+        !no_fail_fast,
+        dry_run,
+    )?;
 
     let mut pending: Vec<SourceCommit> = source_commits
         .iter()
@@ -148,6 +356,17 @@ pub fn sync(
         pending.truncate(limit);
     }
 
+    let entries: Vec<SyncPlanEntry> = source_commits
+        .iter()
+        .map(|commit| SyncPlanEntry {
+            read_repo: commit.source_repo_hint.clone(),
+            source_commit_sha: commit.sha.clone(),
+            source_commit_date: format_source_date(commit.author_date_seconds, commit.author_offset_minutes),
+            planned_vanity_commit: build_commit_message(commit),
+            already_present: existing_markers.contains(&commit.sha),
+        })
+        .collect();
+
     let progress = progress_bar(pending.len() as u64, "Creating vanity commits");
     for commit in &pending {
         let message = build_commit_message(commit);
@@ -158,10 +377,20 @@ pub fn sync(
     }
     progress.finish_and_clear();
 
+    let pushed_ref = if push && !dry_run {
+        Some(push_this_repo(&this_repo)?)
+    } else {
+        None
+    };
+
     Ok(SyncSummary {
         total_source_commits: source_commits.len(),
         existing_markers: existing_markers.len(),
         created: pending.len(),
+        pushed_ref,
+        entries,
+        read_repos_total,
+        read_repo_failures,
     })
 }
 
@@ -169,12 +398,243 @@ fn config_path() -> PathBuf {
     APP_HOME.file_path(CONFIG_FILENAME)
 }
 
+fn sync_state_path() -> PathBuf {
+    APP_HOME.file_path(SYNC_STATE_FILENAME)
+}
+
+fn load_sync_state_cache() -> Result<Vec<RepoCacheEntry>> {
+    load_sync_state_cache_from(&sync_state_path())
+}
+
+/// Parses the sync-state cache serialization read from `path`. Split out
+/// from [`load_sync_state_cache`] so the round-trip with
+/// [`save_sync_state_cache_to`] can be tested against a temp file instead of
+/// the process-global `APP_HOME`.
+fn load_sync_state_cache_from(path: &Path) -> Result<Vec<RepoCacheEntry>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("Failed to read sync-state cache {}", path.display()))?;
+
+    let mut entries = Vec::new();
+    let mut current: Option<RepoCacheEntry> = None;
+    for line in content.lines() {
+        if let Some(value) = line.strip_prefix("repo=") {
+            if let Some(entry) = current.take() {
+                entries.push(entry);
+            }
+            current = Some(RepoCacheEntry {
+                canonical_path: PathBuf::from(value),
+                origin_hint: String::new(),
+                tips: Vec::new(),
+                commits: Vec::new(),
+            });
+            continue;
+        }
+        let Some(entry) = current.as_mut() else {
+            continue;
+        };
+        if let Some(value) = line.strip_prefix("origin=") {
+            entry.origin_hint = value.to_owned();
+            continue;
+        }
+        if let Some(value) = line.strip_prefix("tips=") {
+            entry.tips = value
+                .split(',')
+                .filter(|oid| !oid.is_empty())
+                .filter_map(|oid| Oid::from_str(oid).ok())
+                .collect();
+            continue;
+        }
+        if let Some(value) = line.strip_prefix("commit=") {
+            let mut fields = value.splitn(6, '\t');
+            if let (Some(sha), Some(email), Some(name), Some(date), Some(offset), Some(subject)) = (
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+            ) {
+                if let (Ok(author_date_seconds), Ok(author_offset_minutes)) =
+                    (date.parse(), offset.parse())
+                {
+                    entry.commits.push(CachedSourceCommit {
+                        sha: sha.to_owned(),
+                        author_email: (!email.is_empty()).then(|| email.to_owned()),
+                        author_name: (!name.is_empty()).then(|| name.to_owned()),
+                        author_date_seconds,
+                        author_offset_minutes,
+                        subject: subject.to_owned(),
+                    });
+                }
+            }
+        }
+    }
+    if let Some(entry) = current.take() {
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+fn save_sync_state_cache(entries: &[RepoCacheEntry]) -> Result<()> {
+    APP_HOME.ensure_dir()?;
+    save_sync_state_cache_to(&sync_state_path(), entries)
+}
+
+/// Serializes the sync-state cache to `path`. Split out from
+/// [`save_sync_state_cache`] so the round-trip with
+/// [`load_sync_state_cache_from`] can be tested against a temp file instead
+/// of the process-global `APP_HOME`.
+fn save_sync_state_cache_to(path: &Path, entries: &[RepoCacheEntry]) -> Result<()> {
+    let mut lines = Vec::new();
+    for entry in entries {
+        lines.push(format!("repo={}", entry.canonical_path.display()));
+        lines.push(format!("origin={}", entry.origin_hint));
+        let tips = entry
+            .tips
+            .iter()
+            .map(Oid::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        lines.push(format!("tips={tips}"));
+        for commit in &entry.commits {
+            let subject = commit.subject.replace(['\t', '\n'], " ");
+            let email = commit.author_email.as_deref().unwrap_or("");
+            let name = commit.author_name.as_deref().unwrap_or("").replace(['\t', '\n'], " ");
+            lines.push(format!(
+                "commit={}\t{email}\t{name}\t{}\t{}\t{subject}",
+                commit.sha, commit.author_date_seconds, commit.author_offset_minutes
+            ));
+        }
+    }
+    std::fs::write(path, lines.join("\n"))
+        .wrap_err_with(|| format!("Failed to write sync-state cache {}", path.display()))
+}
+
+fn read_repo_cache_dir(url: &str) -> PathBuf {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hash;
+    use std::hash::Hasher;
+
+    let mut hasher = DefaultHasher::new();
+    url.hash(&mut hasher);
+    let slug: String = url
+        .trim_end_matches(".git")
+        .rsplit(['/', ':'])
+        .take(2)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect::<Vec<_>>()
+        .join("-")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    APP_HOME.file_path(&format!("read-repo-cache/{slug}-{:016x}", hasher.finish()))
+}
+
+/// Resolves URL-backed read-repos for a `--dry-run` preview without cloning
+/// or fetching anything: reuses whatever managed cache already exists on
+/// disk from a prior real sync, and otherwise skips the spec entirely (there
+/// is nothing to preview for a repo that's never been synced yet).
+fn dry_run_read_repos(specs: &[RepoSpec]) -> Vec<PathBuf> {
+    specs
+        .iter()
+        .filter(|spec| spec.cache_dir.exists())
+        .map(|spec| spec.cache_dir.clone())
+        .collect()
+}
+
+/// Clones or fetches every URL-backed read-repo into its managed cache
+/// directory and returns the resulting local paths, ready to be walked like
+/// any other read-repo.
+fn sync_remote_read_repos(specs: &[RepoSpec]) -> Result<Vec<PathBuf>> {
+    let progress = progress_bar(specs.len() as u64, "Syncing remote read-repos");
+    let mut cache_dirs = Vec::with_capacity(specs.len());
+    for spec in specs {
+        clone_or_fetch_read_repo(spec)?;
+        cache_dirs.push(spec.cache_dir.clone());
+        progress.inc(1);
+    }
+    progress.finish_and_clear();
+    Ok(cache_dirs)
+}
+
+/// Clones or fetches a URL-backed read-repo's managed bare mirror by
+/// shelling out to the system `git` binary (rather than `git2`'s own
+/// transport) so authentication is handled by the user's existing
+/// credential helper / ssh-agent setup instead of reimplementing it.
+fn clone_or_fetch_read_repo(spec: &RepoSpec) -> Result<()> {
+    if spec.cache_dir.exists() {
+        GitCommand::new("git")
+            .arg("fetch")
+            .arg("--prune")
+            .arg(&spec.url)
+            .arg("+refs/*:refs/*")
+            .current_dir(spec.cache_dir.as_path())
+            .stdout_mode(StreamMode::Print)
+            .stderr_mode(StreamMode::Print)
+            .run()
+            .wrap_err_with(|| format!("Failed to fetch read-repo {}", spec.url))?;
+    } else {
+        if let Some(parent) = spec.cache_dir.parent() {
+            std::fs::create_dir_all(parent).wrap_err_with(|| {
+                format!("Failed to create cache directory {}", parent.display())
+            })?;
+        }
+        GitCommand::new("git")
+            .arg("clone")
+            .arg("--bare")
+            .arg(&spec.url)
+            .arg(&spec.cache_dir)
+            .stdout_mode(StreamMode::Print)
+            .stderr_mode(StreamMode::Print)
+            .run()
+            .wrap_err_with(|| {
+                format!(
+                    "Failed to clone {} into {}",
+                    spec.url,
+                    spec.cache_dir.display()
+                )
+            })?;
+    }
+    Ok(())
+}
+
+/// Canonicalizes `path` and confirms it is a git repository (worktree or
+/// bare) by shelling out to the system `git` binary, the same one `sync`
+/// already requires via [`crate::git_command::check_git_available`].
+/// Probes are run with [`FailureMode::Ignore`] because a non-zero exit from
+/// either `rev-parse` is an expected "no" answer, not itself an error; only
+/// failing both probes is.
 fn canonicalize_git_repo(path: &Path) -> Result<PathBuf> {
     let canonical = path
         .canonicalize()
         .wrap_err_with(|| format!("Failed to canonicalize path {}", path.display()))?;
-    Repository::open(&canonical)
-        .wrap_err_with(|| format!("Path is not a git repository: {}", canonical.display()))?;
+
+    let is_work_tree = GitCommand::new("git")
+        .arg("rev-parse")
+        .arg("--is-inside-work-tree")
+        .current_dir(canonical.as_path())
+        .failure_mode(FailureMode::Ignore)
+        .run()
+        .wrap_err_with(|| format!("Failed to invoke git to validate {}", canonical.display()))?;
+    let is_bare = GitCommand::new("git")
+        .arg("rev-parse")
+        .arg("--is-bare-repository")
+        .current_dir(canonical.as_path())
+        .failure_mode(FailureMode::Ignore)
+        .run()
+        .wrap_err_with(|| format!("Failed to invoke git to validate {}", canonical.display()))?;
+
+    let answered_yes = |output: &GitCommandOutput| {
+        output.status.success() && output.stdout.as_deref().map(str::trim) == Some("true")
+    };
+    if !answered_yes(&is_work_tree) && !answered_yes(&is_bare) {
+        bail!("Path is not a git repository: {}", canonical.display());
+    }
     Ok(canonical)
 }
 
@@ -207,49 +667,290 @@ fn existing_mirrored_shas(repo: &Repository) -> Result<HashSet<String>> {
     Ok(result)
 }
 
-fn gather_source_commits(read_repos: &[PathBuf]) -> Result<Vec<SourceCommit>> {
-    let per_repo_results: Vec<Result<Vec<SourceCommit>>> = read_repos
-        .par_iter()
-        .map(|repo_path| gather_source_commits_for_repo(repo_path.as_path()))
-        .collect();
+/// Resolves which author identities to mirror, in priority order: an explicit
+/// `--author` override, then configured `author=` lines, then the `user.email`
+/// already resolved for `this_repo` so the default mirrors the current user.
+fn resolve_author_patterns(
+    config: &VanityConfig,
+    author_override: Option<&str>,
+    this_repo: &Repository,
+) -> Vec<String> {
+    if let Some(author) = author_override {
+        return vec![author.to_owned()];
+    }
+    if !config.authors.is_empty() {
+        return config.authors.clone();
+    }
+    let (_, email) = resolve_repo_identity(this_repo);
+    vec![email]
+}
+
+#[derive(Clone, Debug)]
+enum AuthorMatcher {
+    Email(String),
+    NameRegex(Regex),
+}
+
+fn build_author_matchers(patterns: &[String]) -> Vec<AuthorMatcher> {
+    patterns
+        .iter()
+        .filter_map(|pattern| {
+            let trimmed = pattern.trim();
+            if trimmed.is_empty() {
+                return None;
+            }
+            if trimmed.contains('@') {
+                Some(AuthorMatcher::Email(trimmed.to_lowercase()))
+            } else {
+                Regex::new(trimmed).ok().map(AuthorMatcher::NameRegex)
+            }
+        })
+        .collect()
+}
+
+fn author_matches(matchers: &[AuthorMatcher], author_email: Option<&str>, author_name: Option<&str>) -> bool {
+    matchers.iter().any(|matcher| match matcher {
+        AuthorMatcher::Email(expected) => author_email
+            .map(|email| email.eq_ignore_ascii_case(expected))
+            .unwrap_or(false),
+        AuthorMatcher::NameRegex(regex) => author_name
+            .map(|name| regex.is_match(name))
+            .unwrap_or(false),
+    })
+}
+
+/// Reads every read-repo's commit history concurrently, bounded by
+/// `concurrency` (defaults to rayon's own worker count) and, if
+/// `per_repo_timeout` is set, aborts waiting on any single repo that takes
+/// longer than that so one slow or unreachable repo can't starve the others.
+///
+/// When `fail_fast` is true (the default), the first repo failure aborts the
+/// whole read phase. When false (`--no-fail-fast`), failures are collected
+/// and returned alongside whatever commits the other repos produced.
+///
+/// When `dry_run` is true, the updated per-repo cache is computed (so the
+/// revwalk-skipping logic still runs identically) but never written to
+/// `sync-state.txt`, since a preview shouldn't mutate on-disk state.
+fn gather_source_commits(
+    read_repos: &[PathBuf],
+    author_patterns: &[String],
+    forges: &[(String, String)],
+    concurrency: Option<usize>,
+    per_repo_timeout: Option<Duration>,
+    fail_fast: bool,
+    dry_run: bool,
+) -> Result<(Vec<SourceCommit>, Vec<ReadRepoFailure>)> {
+    let matchers = build_author_matchers(author_patterns);
+    let cache_entries = load_sync_state_cache()?;
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(concurrency.unwrap_or_else(rayon::current_num_threads))
+        .build()
+        .wrap_err("Failed to build read-repo worker pool")?;
+
+    let per_repo_results: Vec<Result<(Vec<SourceCommit>, RepoCacheEntry)>> = pool.install(|| {
+        read_repos
+            .par_iter()
+            .map(|repo_path| {
+                gather_source_commits_for_repo_bounded(
+                    repo_path.as_path(),
+                    &matchers,
+                    forges,
+                    &cache_entries,
+                    per_repo_timeout,
+                )
+            })
+            .collect()
+    });
 
     let mut all = Vec::new();
     let mut seen_shas: HashSet<String> = HashSet::new();
+    let mut updated_cache_entries = Vec::with_capacity(read_repos.len());
+    let mut failures = Vec::new();
 
-    for per_repo in per_repo_results {
-        for commit in per_repo? {
-            if seen_shas.insert(commit.sha.clone()) {
-                all.push(commit);
+    for (repo_path, per_repo) in read_repos.iter().zip(per_repo_results) {
+        match per_repo {
+            Ok((commits, cache_entry)) => {
+                for commit in commits {
+                    if seen_shas.insert(commit.sha.clone()) {
+                        all.push(commit);
+                    }
+                }
+                updated_cache_entries.push(cache_entry);
+            }
+            Err(err) => {
+                if fail_fast {
+                    return Err(err);
+                }
+                failures.push(ReadRepoFailure {
+                    read_repo: repo_path.display().to_string(),
+                    message: format!("{err:#}"),
+                });
             }
         }
     }
 
+    if !dry_run {
+        save_sync_state_cache(&updated_cache_entries)?;
+    }
+
     all.sort_by(|left, right| {
         left.author_date_seconds
             .cmp(&right.author_date_seconds)
             .then_with(|| left.sha.cmp(&right.sha))
     });
 
-    Ok(all)
+    Ok((all, failures))
+}
+
+/// Worker threads spawned by [`gather_source_commits_for_repo_bounded`] whose
+/// `recv_timeout` expired before the revwalk finished. The thread (and its
+/// open `Repository`) keeps running after we stop waiting on it, so we can't
+/// drop the `JoinHandle` outright without leaking it for the rest of the
+/// process; stashing it here lets [`reap_abandoned_read_repo_workers`] join
+/// it once it actually finishes, and caps how many can pile up under
+/// `--watch` against a persistently-slow repo.
+static ABANDONED_READ_REPO_WORKERS: std::sync::Mutex<Vec<thread::JoinHandle<()>>> =
+    std::sync::Mutex::new(Vec::new());
+
+/// Once this many timed-out workers are still outstanding, the next one to
+/// stash blocks (joining the oldest) rather than letting the backlog grow
+/// further, trading a bit of latency for a hard cap on leaked threads.
+const MAX_ABANDONED_READ_REPO_WORKERS: usize = 32;
+
+/// Joins whichever abandoned workers have since finished, then stashes
+/// `handle`, blocking to join the oldest entries if the backlog is at cap.
+fn reap_abandoned_read_repo_workers(handle: thread::JoinHandle<()>) {
+    let mut workers = ABANDONED_READ_REPO_WORKERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    let mut still_running = Vec::with_capacity(workers.len());
+    for worker in workers.drain(..) {
+        if worker.is_finished() {
+            let _ = worker.join();
+        } else {
+            still_running.push(worker);
+        }
+    }
+    *workers = still_running;
+
+    workers.push(handle);
+    while workers.len() > MAX_ABANDONED_READ_REPO_WORKERS {
+        let oldest = workers.remove(0);
+        drop(workers);
+        let _ = oldest.join();
+        workers = ABANDONED_READ_REPO_WORKERS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+    }
+}
+
+/// Runs `gather_source_commits_for_repo` on a dedicated thread and enforces
+/// `timeout` (if set) via a channel recv, so a single unreachable repo fails
+/// with a clear timeout error instead of hanging the whole sync. On timeout
+/// the worker thread is handed off to [`reap_abandoned_read_repo_workers`]
+/// rather than dropped, so it's joined (and its `Repository` freed) once it
+/// eventually finishes instead of leaking for the rest of the process.
+fn gather_source_commits_for_repo_bounded(
+    repo_path: &Path,
+    author_matchers: &[AuthorMatcher],
+    forges: &[(String, String)],
+    cache_entries: &[RepoCacheEntry],
+    timeout: Option<Duration>,
+) -> Result<(Vec<SourceCommit>, RepoCacheEntry)> {
+    let Some(timeout) = timeout else {
+        return gather_source_commits_for_repo(repo_path, author_matchers, forges, cache_entries);
+    };
+
+    let repo_path = repo_path.to_path_buf();
+    let repo_path_for_thread = repo_path.clone();
+    let author_matchers = author_matchers.to_vec();
+    let forges = forges.to_vec();
+    let cache_entries = cache_entries.to_vec();
+    let (tx, rx) = mpsc::channel();
+    let handle = thread::Builder::new()
+        .spawn(move || {
+            let result = gather_source_commits_for_repo(
+                &repo_path_for_thread,
+                &author_matchers,
+                &forges,
+                &cache_entries,
+            );
+            let _ = tx.send(result);
+        })
+        .wrap_err("Failed to spawn read-repo worker thread")?;
+
+    match rx.recv_timeout(timeout) {
+        Ok(result) => {
+            let _ = handle.join();
+            result
+        }
+        Err(_) => {
+            reap_abandoned_read_repo_workers(handle);
+            Err(eyre::eyre!(
+                "Timed out after {:?} reading read-repo at {}",
+                timeout,
+                repo_path.display()
+            ))
+        }
+    }
 }
 
-fn gather_source_commits_for_repo(repo_path: &Path) -> Result<Vec<SourceCommit>> {
+/// Walks `repo_path` for every commit reachable from its ref tips, skipping
+/// the revwalk entirely when those tips are unchanged from `cache_entries`,
+/// and otherwise only walking the commits newly reachable since the cached
+/// tips (existing de-dup by sha remains the correctness backstop). The cache
+/// stores every commit regardless of author, since `author_matchers` can
+/// change between runs on unmoved tips; filtering by `author_matchers`
+/// happens on every call, whether the walk ran or the cache hit. Returns both
+/// the resulting commits and the cache entry to persist for next time.
+fn gather_source_commits_for_repo(
+    repo_path: &Path,
+    author_matchers: &[AuthorMatcher],
+    forges: &[(String, String)],
+    cache_entries: &[RepoCacheEntry],
+) -> Result<(Vec<SourceCommit>, RepoCacheEntry)> {
     let repo = Repository::open(repo_path)
         .wrap_err_with(|| format!("Failed to open read-repo at {}", repo_path.display()))?;
     let source_hint = repo_origin_url(&repo).unwrap_or_else(|| repo_path.display().to_string());
-    let source_web = derive_github_web_base(&source_hint);
+    let (source_web, forge_kind) = derive_forge_web_base(&source_hint, forges).unzip();
+    let forge_kind = forge_kind.unwrap_or_default();
+
+    let canonical_path = repo_path
+        .canonicalize()
+        .unwrap_or_else(|_| repo_path.to_path_buf());
+    let current_tips = collect_ref_tips(&repo)?;
+
+    let cached = cache_entries
+        .iter()
+        .find(|entry| entry.canonical_path == canonical_path && entry.origin_hint == source_hint);
+
+    if let Some(entry) = cached {
+        if entry.tips == current_tips {
+            let commits = filter_cached_commits(
+                &entry.commits,
+                author_matchers,
+                &source_hint,
+                &source_web,
+                &forge_kind,
+            );
+            return Ok((commits, entry.clone()));
+        }
+    }
 
     let mut walk = repo.revwalk()?;
     walk.set_sorting(Sort::TOPOLOGICAL | Sort::TIME)?;
-
-    for reference in repo.references()? {
-        let reference = reference?;
-        if let Some(target) = reference.target() {
-            let _ = walk.push(target);
+    for tip in &current_tips {
+        walk.push(*tip)?;
+    }
+    if let Some(entry) = cached {
+        for old_tip in &entry.tips {
+            let _ = walk.hide(*old_tip);
         }
     }
 
-    let mut commits = Vec::new();
+    let mut new_cached_commits = Vec::new();
     let mut seen_oids = HashSet::new();
     for oid in walk {
         let oid = oid?;
@@ -257,18 +958,97 @@ fn gather_source_commits_for_repo(repo_path: &Path) -> Result<Vec<SourceCommit>>
             continue;
         }
         let commit = repo.find_commit(oid)?;
-        let author_time = commit.author().when();
-        commits.push(SourceCommit {
+        let author = commit.author();
+        let author_time = author.when();
+        new_cached_commits.push(CachedSourceCommit {
             sha: oid.to_string(),
-            source_repo_hint: source_hint.clone(),
-            source_web_base_url: source_web.clone(),
+            author_email: author.email().map(str::to_lowercase),
+            author_name: author.name().map(str::to_owned),
             author_date_seconds: author_time.seconds(),
             author_offset_minutes: author_time.offset_minutes(),
             subject: commit.summary().unwrap_or("").to_owned(),
         });
     }
 
-    Ok(commits)
+    // Commits already cached from a prior run remain valid mirror targets
+    // even if a history rewrite made them unreachable from the current tips.
+    let all_cached_commits = match cached {
+        Some(entry) => {
+            let mut merged = entry.commits.clone();
+            merged.extend(new_cached_commits);
+            merged
+        }
+        None => new_cached_commits,
+    };
+
+    let commits = filter_cached_commits(
+        &all_cached_commits,
+        author_matchers,
+        &source_hint,
+        &source_web,
+        &forge_kind,
+    );
+
+    let updated_entry = RepoCacheEntry {
+        canonical_path,
+        origin_hint: source_hint,
+        tips: current_tips,
+        commits: all_cached_commits,
+    };
+
+    Ok((commits, updated_entry))
+}
+
+/// Applies `author_matchers` to a cached commit set, since the cache itself
+/// is unfiltered (see [`RepoCacheEntry`]).
+fn filter_cached_commits(
+    cached: &[CachedSourceCommit],
+    author_matchers: &[AuthorMatcher],
+    source_hint: &str,
+    source_web: &Option<String>,
+    forge_kind: &ForgeKind,
+) -> Vec<SourceCommit> {
+    cached
+        .iter()
+        .filter(|commit| {
+            author_matches(
+                author_matchers,
+                commit.author_email.as_deref(),
+                commit.author_name.as_deref(),
+            )
+        })
+        .map(|commit| to_source_commit(commit, source_hint, source_web, forge_kind))
+        .collect()
+}
+
+fn collect_ref_tips(repo: &Repository) -> Result<Vec<Oid>> {
+    let mut tips = Vec::new();
+    for reference in repo.references()? {
+        let reference = reference?;
+        if let Some(target) = reference.target() {
+            tips.push(target);
+        }
+    }
+    tips.sort();
+    tips.dedup();
+    Ok(tips)
+}
+
+fn to_source_commit(
+    cached: &CachedSourceCommit,
+    source_hint: &str,
+    source_web: &Option<String>,
+    forge_kind: &ForgeKind,
+) -> SourceCommit {
+    SourceCommit {
+        sha: cached.sha.clone(),
+        source_repo_hint: source_hint.to_owned(),
+        source_web_base_url: source_web.clone(),
+        forge_kind: forge_kind.clone(),
+        author_date_seconds: cached.author_date_seconds,
+        author_offset_minutes: cached.author_offset_minutes,
+        subject: cached.subject.clone(),
+    }
 }
 
 fn repo_origin_url(repo: &Repository) -> Option<String> {
@@ -277,29 +1057,62 @@ fn repo_origin_url(repo: &Repository) -> Option<String> {
         .and_then(|remote| remote.url().map(ToOwned::to_owned))
 }
 
-fn derive_github_web_base(source_hint: &str) -> Option<String> {
-    let hint = source_hint.trim();
-    if let Some(path) = hint.strip_prefix("git@github.com:") {
-        return Some(format!(
-            "https://github.com/{}",
-            path.trim_end_matches(".git")
-        ));
+/// Splits a remote URL (`git@host:owner/repo`, `https://host/owner/repo`,
+/// `ssh://git@host/owner/repo`) into a lowercased host and a `owner/repo`-style
+/// path, with any `.git` suffix and surrounding slashes stripped.
+fn parse_remote_origin(url: &str) -> Option<(String, String)> {
+    let trimmed = url.trim();
+    if let Some(rest) = trimmed.strip_prefix("git@") {
+        let (host, path) = rest.split_once(':')?;
+        let path = path.trim_matches('/').trim_end_matches(".git").trim_end_matches('/');
+        return Some((host.to_lowercase(), path.to_owned()));
     }
-    if hint.starts_with("https://github.com/") || hint.starts_with("http://github.com/") {
-        return Some(
-            hint.trim_end_matches(".git")
-                .trim_end_matches('/')
-                .to_owned(),
-        );
+    for prefix in ["https://", "http://", "ssh://git@", "ssh://"] {
+        if let Some(rest) = trimmed.strip_prefix(prefix) {
+            let rest = rest.trim_end_matches(".git").trim_end_matches('/');
+            let (host, path) = rest.split_once('/')?;
+            return Some((host.to_lowercase(), path.to_owned()));
+        }
     }
     None
 }
 
+fn classify_forge(host: &str, forges: &[(String, String)]) -> ForgeKind {
+    if let Some((_, template)) = forges.iter().find(|(known_host, _)| known_host == host) {
+        return ForgeKind::Custom(template.clone());
+    }
+    if host.contains("gitlab") {
+        ForgeKind::GitLab
+    } else if host.contains("bitbucket") {
+        ForgeKind::Bitbucket
+    } else if host.contains("github.com") {
+        ForgeKind::GitHub
+    } else {
+        // Gitea/Forgejo (and most self-hosted git servers) follow the same
+        // `/commit/<sha>` convention as GitHub unless overridden by `forges`.
+        ForgeKind::Gitea
+    }
+}
+
+fn derive_forge_web_base(
+    source_hint: &str,
+    forges: &[(String, String)],
+) -> Option<(String, ForgeKind)> {
+    let (host, path) = parse_remote_origin(source_hint)?;
+    let kind = classify_forge(&host, forges);
+    Some((format!("https://{host}/{path}"), kind))
+}
+
 fn source_commit_url(commit: &SourceCommit) -> Option<String> {
-    commit
-        .source_web_base_url
-        .as_ref()
-        .map(|base| format!("{}/commit/{}", base.trim_end_matches('/'), commit.sha))
+    let base = commit.source_web_base_url.as_ref()?;
+    let base = base.trim_end_matches('/');
+    let path = match &commit.forge_kind {
+        ForgeKind::GitHub | ForgeKind::Gitea => format!("/commit/{}", commit.sha),
+        ForgeKind::GitLab => format!("/-/commit/{}", commit.sha),
+        ForgeKind::Bitbucket => format!("/commits/{}", commit.sha),
+        ForgeKind::Custom(template) => template.replace("{sha}", &commit.sha),
+    };
+    Some(format!("{base}{path}"))
 }
 
 fn format_source_date(seconds: i64, offset_minutes: i32) -> String {
@@ -359,6 +1172,34 @@ fn create_empty_commit(repo: &Repository, message: &str, source: &SourceCommit)
     .wrap_err("Failed to create empty vanity commit")
 }
 
+/// Pushes `this-repo`'s current branch to `origin` via the system `git`
+/// binary (see [`clone_or_fetch_read_repo`] for why), returning the pushed ref.
+fn push_this_repo(repo: &Repository) -> Result<String> {
+    let head = repo.head().wrap_err("Failed to resolve this-repo HEAD")?;
+    let branch_name = head
+        .shorthand()
+        .ok_or_else(|| eyre::eyre!("this-repo HEAD is not a branch"))?
+        .to_owned();
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| eyre::eyre!("this-repo has no working directory (bare repos can't be pushed from)"))?;
+    repo.find_remote("origin")
+        .wrap_err("Missing origin remote in this-repo")?;
+    let refspec = format!("refs/heads/{branch_name}:refs/heads/{branch_name}");
+
+    GitCommand::new("git")
+        .arg("push")
+        .arg("origin")
+        .arg(&refspec)
+        .current_dir(workdir)
+        .stdout_mode(StreamMode::Print)
+        .stderr_mode(StreamMode::Print)
+        .run()
+        .wrap_err_with(|| format!("Failed to push {branch_name} to origin"))?;
+
+    Ok(branch_name)
+}
+
 fn resolve_repo_identity(repo: &Repository) -> (String, String) {
     let Ok(config) = repo.config() else {
         return ("Vanity".to_owned(), "vanity@example.invalid".to_owned());
@@ -376,15 +1217,20 @@ fn resolve_repo_identity(repo: &Repository) -> (String, String) {
     (name, email)
 }
 
+/// Case-folds both the host and the `owner/repo` path, since GitHub (and
+/// most forges) treat those paths case-insensitively and this is used to
+/// compare against [`EXPECTED_VANITY_REMOTE`] in the mutation safety gate
+/// (see [`assert_vanity_target_repo`]) — an origin that differs only in path
+/// case must still compare equal.
 fn normalize_remote_url(url: &str) -> String {
-    let mut normalized = url.trim().to_lowercase();
-    if let Some(path) = normalized.strip_prefix("git@github.com:") {
-        normalized = format!("https://github.com/{path}");
+    if let Some((host, path)) = parse_remote_origin(url) {
+        return format!("https://{host}/{}", path.to_lowercase());
     }
-    if let Some(stripped) = normalized.strip_suffix(".git") {
-        normalized = stripped.to_owned();
-    }
-    normalized.trim_end_matches('/').to_owned()
+    url.trim()
+        .to_lowercase()
+        .trim_end_matches(".git")
+        .trim_end_matches('/')
+        .to_owned()
 }
 
 fn assert_vanity_target_repo(repo: &Repository, allow_non_vanity_target: bool) -> Result<()> {
@@ -452,6 +1298,7 @@ mod tests {
             sha: "0123456789abcdef0123456789abcdef01234567".to_owned(),
             source_repo_hint: "source".to_owned(),
             source_web_base_url: None,
+            forge_kind: ForgeKind::default(),
             author_date_seconds: 1_700_000_001,
             author_offset_minutes: 0,
             subject: "subject".to_owned(),
@@ -467,4 +1314,209 @@ mod tests {
         drop(repo);
         let _ = std::fs::remove_dir_all(&repo_dir);
     }
+
+    #[test]
+    fn parse_remote_origin_handles_scp_and_url_styles() {
+        assert_eq!(
+            parse_remote_origin("git@github.com:TeamDman/Vanity.git"),
+            Some(("github.com".to_owned(), "TeamDman/Vanity".to_owned()))
+        );
+        assert_eq!(
+            parse_remote_origin("https://github.com/TeamDman/Vanity.git"),
+            Some(("github.com".to_owned(), "TeamDman/Vanity".to_owned()))
+        );
+        assert_eq!(
+            parse_remote_origin("https://gitlab.com/group/sub/project/"),
+            Some(("gitlab.com".to_owned(), "group/sub/project".to_owned()))
+        );
+        assert_eq!(
+            parse_remote_origin("ssh://git@example.org/TeamDman/Vanity"),
+            Some(("example.org".to_owned(), "TeamDman/Vanity".to_owned()))
+        );
+        assert_eq!(parse_remote_origin("not-a-remote-url"), None);
+    }
+
+    #[test]
+    fn classify_forge_recognizes_known_hosts_and_falls_back_to_gitea() {
+        assert_eq!(classify_forge("github.com", &[]), ForgeKind::GitHub);
+        assert_eq!(classify_forge("gitlab.com", &[]), ForgeKind::GitLab);
+        assert_eq!(classify_forge("bitbucket.org", &[]), ForgeKind::Bitbucket);
+        assert_eq!(classify_forge("git.example.org", &[]), ForgeKind::Gitea);
+
+        let forges = vec![("git.example.org".to_owned(), "/commits/{sha}".to_owned())];
+        assert_eq!(
+            classify_forge("git.example.org", &forges),
+            ForgeKind::Custom("/commits/{sha}".to_owned())
+        );
+    }
+
+    #[test]
+    fn source_commit_url_formats_each_forge_kind() {
+        let base = |forge_kind: ForgeKind| SourceCommit {
+            sha: "0123456789abcdef0123456789abcdef01234567".to_owned(),
+            source_repo_hint: "source".to_owned(),
+            source_web_base_url: Some("https://example.org/owner/repo".to_owned()),
+            forge_kind,
+            author_date_seconds: 0,
+            author_offset_minutes: 0,
+            subject: String::new(),
+        };
+
+        assert_eq!(
+            source_commit_url(&base(ForgeKind::GitHub)),
+            Some("https://example.org/owner/repo/commit/0123456789abcdef0123456789abcdef01234567".to_owned())
+        );
+        assert_eq!(
+            source_commit_url(&base(ForgeKind::Gitea)),
+            Some("https://example.org/owner/repo/commit/0123456789abcdef0123456789abcdef01234567".to_owned())
+        );
+        assert_eq!(
+            source_commit_url(&base(ForgeKind::GitLab)),
+            Some("https://example.org/owner/repo/-/commit/0123456789abcdef0123456789abcdef01234567".to_owned())
+        );
+        assert_eq!(
+            source_commit_url(&base(ForgeKind::Bitbucket)),
+            Some("https://example.org/owner/repo/commits/0123456789abcdef0123456789abcdef01234567".to_owned())
+        );
+        assert_eq!(
+            source_commit_url(&base(ForgeKind::Custom("/changeset/{sha}".to_owned()))),
+            Some("https://example.org/owner/repo/changeset/0123456789abcdef0123456789abcdef01234567".to_owned())
+        );
+
+        let mut no_base = base(ForgeKind::GitHub);
+        no_base.source_web_base_url = None;
+        assert_eq!(source_commit_url(&no_base), None);
+    }
+
+    #[test]
+    fn sync_state_cache_round_trips_through_disk() {
+        let path = std::env::temp_dir().join(format!(
+            "vanity-sync-state-test-{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("time should be after unix epoch")
+                .as_nanos()
+        ));
+
+        let entries = vec![RepoCacheEntry {
+            canonical_path: PathBuf::from("/repos/example"),
+            origin_hint: "https://github.com/TeamDman/Vanity".to_owned(),
+            tips: vec![
+                Oid::from_str("0123456789abcdef0123456789abcdef01234567").expect("valid oid"),
+                Oid::from_str("fedcba9876543210fedcba9876543210fedcba9").expect("valid oid"),
+            ],
+            commits: vec![
+                CachedSourceCommit {
+                    sha: "0123456789abcdef0123456789abcdef01234567".to_owned(),
+                    author_email: Some("alice@example.com".to_owned()),
+                    author_name: Some("Alice Example".to_owned()),
+                    author_date_seconds: 1_700_000_000,
+                    author_offset_minutes: -60,
+                    subject: "a subject\nwith a tab\tand a newline".to_owned(),
+                },
+                CachedSourceCommit {
+                    sha: "fedcba9876543210fedcba9876543210fedcba9".to_owned(),
+                    author_email: None,
+                    author_name: None,
+                    author_date_seconds: 1_700_000_100,
+                    author_offset_minutes: 0,
+                    subject: "no author identity".to_owned(),
+                },
+            ],
+        }];
+
+        save_sync_state_cache_to(&path, &entries).expect("cache should save");
+        let loaded = load_sync_state_cache_from(&path).expect("cache should load");
+
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].canonical_path, entries[0].canonical_path);
+        assert_eq!(loaded[0].origin_hint, entries[0].origin_hint);
+        assert_eq!(loaded[0].tips, entries[0].tips);
+        assert_eq!(loaded[0].commits.len(), 2);
+        assert_eq!(loaded[0].commits[0].sha, "0123456789abcdef0123456789abcdef01234567");
+        assert_eq!(loaded[0].commits[0].author_email.as_deref(), Some("alice@example.com"));
+        assert_eq!(loaded[0].commits[0].author_name.as_deref(), Some("Alice Example"));
+        assert_eq!(loaded[0].commits[0].author_date_seconds, 1_700_000_000);
+        assert_eq!(loaded[0].commits[0].author_offset_minutes, -60);
+        assert_eq!(loaded[0].commits[0].subject, "a subject with a tab and a newline");
+        assert_eq!(loaded[0].commits[1].author_email, None);
+        assert_eq!(loaded[0].commits[1].author_name, None);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn load_sync_state_cache_from_missing_file_returns_empty() {
+        let path = std::env::temp_dir().join("vanity-sync-state-does-not-exist");
+        let _ = std::fs::remove_file(&path);
+        let loaded = load_sync_state_cache_from(&path).expect("missing cache should load as empty");
+        assert!(loaded.is_empty());
+    }
+
+    #[test]
+    fn json_escape_escapes_control_and_special_characters() {
+        assert_eq!(json_escape("plain"), "\"plain\"");
+        assert_eq!(json_escape("a\"b\\c"), "\"a\\\"b\\\\c\"");
+        assert_eq!(json_escape("line1\nline2\ttab\rcr"), "\"line1\\nline2\\ttab\\rcr\"");
+        assert_eq!(json_escape("bell\u{7}"), "\"bell\\u0007\"");
+    }
+
+    #[test]
+    fn plan_entries_to_json_serializes_each_field() {
+        let entries = vec![SyncPlanEntry {
+            read_repo: "https://github.com/TeamDman/Vanity".to_owned(),
+            source_commit_sha: "abc123".to_owned(),
+            source_commit_date: "2024-01-01T00:00:00+00:00".to_owned(),
+            planned_vanity_commit: "Vanity mirror: abc123".to_owned(),
+            already_present: true,
+        }];
+        assert_eq!(
+            plan_entries_to_json(&entries),
+            "[{\"read_repo\":\"https://github.com/TeamDman/Vanity\",\"source_commit_sha\":\"abc123\",\"source_commit_date\":\"2024-01-01T00:00:00+00:00\",\"planned_vanity_commit\":\"Vanity mirror: abc123\",\"already_present\":true}]"
+        );
+    }
+
+    #[test]
+    fn plan_entries_to_json_handles_empty_plan() {
+        assert_eq!(plan_entries_to_json(&[]), "[]");
+    }
+
+    #[test]
+    fn build_author_matchers_skips_blank_patterns_and_classifies_by_at_sign() {
+        let matchers = build_author_matchers(&[
+            "alice@example.com".to_owned(),
+            "  ".to_owned(),
+            "^Bob .*$".to_owned(),
+        ]);
+        assert_eq!(matchers.len(), 2);
+        assert!(matches!(&matchers[0], AuthorMatcher::Email(email) if email == "alice@example.com"));
+        assert!(matches!(&matchers[1], AuthorMatcher::NameRegex(_)));
+    }
+
+    #[test]
+    fn author_matches_checks_email_case_insensitively_and_name_by_regex() {
+        let matchers = build_author_matchers(&["Alice@Example.com".to_owned(), "^Bob .*$".to_owned()]);
+
+        assert!(author_matches(&matchers, Some("alice@example.com"), Some("Someone Else")));
+        assert!(author_matches(&matchers, Some("nobody@example.com"), Some("Bob Smith")));
+        assert!(!author_matches(&matchers, Some("nobody@example.com"), Some("Someone Else")));
+    }
+
+    #[test]
+    fn author_matches_skips_commits_with_no_email_against_an_email_matcher() {
+        let matchers = build_author_matchers(&["alice@example.com".to_owned()]);
+        assert!(!author_matches(&matchers, None, Some("Alice")));
+    }
+
+    #[test]
+    fn normalize_remote_url_case_folds_host_and_path() {
+        assert_eq!(
+            normalize_remote_url("https://github.com/TeamDman/Vanity"),
+            normalize_remote_url("https://GitHub.com/teamdman/vanity")
+        );
+        assert_eq!(
+            normalize_remote_url("git@github.com:TeamDman/Vanity.git"),
+            normalize_remote_url("https://github.com/teamdman/vanity")
+        );
+    }
 }